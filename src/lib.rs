@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! rust_finprim - a financial primitives library
+//!
+//! This crate provides building blocks for time-value-of-money and related
+//! financial calculations, implemented on top of [`rust_decimal`] for exact,
+//! reproducible arithmetic.
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+
+use rust_decimal::Decimal;
+
+pub(crate) const ZERO: Decimal = Decimal::ZERO;
+pub(crate) const ONE: Decimal = Decimal::ONE;
+
+pub mod rates;
+pub mod tvm;
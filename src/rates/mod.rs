@@ -0,0 +1,115 @@
+//! Rates - conversions between nominal, periodic, and effective rates
+//!
+//! Converts between a nominal annual rate (APR), an effective rate for a
+//! single compounding period (EPR), and an effective annual rate (EAR).
+
+use crate::ONE;
+use rust_decimal::prelude::*;
+
+/// Converts a nominal annual rate (APR) to an effective periodic rate (EPR).
+///
+/// # Arguments
+/// * `apr` - The nominal annual rate
+/// * `periods_per_year` - The compounding frequency per year
+///
+/// # Returns
+/// * The effective rate for a single period: `apr / periods_per_year`
+///
+/// # Example
+/// ```
+/// use rust_finprim::rates::nominal_to_periodic;
+/// use rust_decimal_macros::*;
+///
+/// // 6% APR compounded monthly
+/// nominal_to_periodic(dec!(0.06), dec!(12));
+/// ```
+pub fn nominal_to_periodic(apr: Decimal, periods_per_year: Decimal) -> Decimal {
+    apr / periods_per_year
+}
+
+/// Converts an effective periodic rate (EPR) to an effective annual rate (EAR).
+///
+/// # Arguments
+/// * `epr` - The effective rate for a single compounding period
+/// * `periods_per_year` - The compounding frequency per year
+///
+/// # Returns
+/// * The effective annual rate: `(1 + epr)^periods_per_year - 1`
+pub fn periodic_to_ear(epr: Decimal, periods_per_year: Decimal) -> Decimal {
+    (ONE + epr).powd(periods_per_year) - ONE
+}
+
+/// Converts an effective annual rate (EAR) to a nominal annual rate (APR)
+/// compounded at the given frequency. The inverse of [`periodic_to_ear`]
+/// composed with [`nominal_to_periodic`].
+///
+/// # Arguments
+/// * `ear` - The effective annual rate
+/// * `periods_per_year` - The compounding frequency per year
+///
+/// # Returns
+/// * The nominal annual rate: `periods_per_year * ((1 + ear)^(1 / periods_per_year) - 1)`
+pub fn ear_to_nominal(ear: Decimal, periods_per_year: Decimal) -> Decimal {
+    periods_per_year * ((ONE + ear).powd(ONE / periods_per_year) - ONE)
+}
+
+/// Converts a continuously-compounded nominal rate directly to an effective
+/// annual rate (EAR). This is the compounding-frequency limit of
+/// [`periodic_to_ear`] as `periods_per_year` approaches infinity.
+///
+/// # Arguments
+/// * `apr` - The continuously-compounded nominal annual rate
+///
+/// # Returns
+/// * The effective annual rate: `e^apr - 1`
+pub fn ear_continuous(apr: Decimal) -> Decimal {
+    apr.exp() - ONE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    use rust_decimal_macros::*;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+    #[cfg(not(feature = "std"))]
+    use std::prelude::v1::*;
+
+    #[test]
+    fn test_nominal_to_periodic() {
+        // 6% APR compounded monthly -> 0.5% per period
+        let result = nominal_to_periodic(dec!(0.06), dec!(12));
+        assert!((result - dec!(0.005)).abs() < dec!(1e-10), "Expected 0.005, got {}", result);
+    }
+
+    #[test]
+    fn test_periodic_to_ear() {
+        // 0.5% per period, 12 periods per year -> ~6.1678% EAR
+        let result = periodic_to_ear(dec!(0.005), dec!(12));
+        assert!((result - dec!(0.061678)).abs() < dec!(1e-5), "Expected ~0.061678, got {}", result);
+    }
+
+    #[test]
+    fn test_ear_to_nominal_roundtrip() {
+        let apr = dec!(0.06);
+        let periods = dec!(12);
+        let epr = nominal_to_periodic(apr, periods);
+        let ear = periodic_to_ear(epr, periods);
+        let roundtrip_apr = ear_to_nominal(ear, periods);
+        assert!(
+            (roundtrip_apr - apr).abs() < dec!(1e-8),
+            "Roundtrip should recover original APR. Expected {}, got {}",
+            apr,
+            roundtrip_apr
+        );
+    }
+
+    #[test]
+    fn test_ear_continuous() {
+        // A 5% continuously-compounded rate -> e^0.05 - 1 ~= 0.051271
+        let result = ear_continuous(dec!(0.05));
+        assert!((result - dec!(0.051271)).abs() < dec!(1e-5), "Expected ~0.051271, got {}", result);
+    }
+}
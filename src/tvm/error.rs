@@ -0,0 +1,32 @@
+use core::fmt;
+
+/// Errors returned by validating TVM functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TvmError {
+    /// The cash flows do not contain both a positive and a negative amount, so no rate of return exists.
+    NoSignChange,
+    /// Newton-Raphson (and the bisection fallback) failed to converge within the iteration cap,
+    /// or the bisection range does not bracket a sign change.
+    DidNotConverge,
+    /// Both `pmt` and `pv` are zero, so there is nothing to grow.
+    ZeroPmtAndPv,
+    /// `nper` is negative.
+    InvalidNper,
+    /// `rate <= -1`, which makes `(1 + rate)^nper` meaningless.
+    InvalidRate,
+}
+
+impl fmt::Display for TvmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TvmError::NoSignChange => write!(f, "cash flows must contain both a positive and a negative amount"),
+            TvmError::DidNotConverge => write!(f, "failed to converge on a solution"),
+            TvmError::ZeroPmtAndPv => write!(f, "at least one of pmt or pv must be non-zero"),
+            TvmError::InvalidNper => write!(f, "nper must be non-negative"),
+            TvmError::InvalidRate => write!(f, "rate must be greater than -1"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TvmError {}
@@ -1,3 +1,4 @@
+use crate::tvm::error::TvmError;
 use crate::{ONE, ZERO};
 use rust_decimal::prelude::*;
 
@@ -11,7 +12,9 @@ use rust_decimal::prelude::*;
 /// It is calculated by applying a rate of return to the initial investment over a specified period of time.
 ///
 /// # Arguments
-/// * `rate` - The interest rate per period
+/// * `rate` - The interest rate per period. If you're holding a quoted nominal/effective
+///   annual rate instead of a per-period rate, convert it first with [`crate::rates`]
+///   (e.g. [`crate::rates::nominal_to_periodic`]).
 /// * `nper` - The number of compounding periods
 /// * `pmt` - The payment amount per period
 /// * `pv` (optional) - The present value, default is 0
@@ -79,6 +82,154 @@ pub fn fv(rate: Decimal, nper: Decimal, pmt: Decimal, pv: Option<Decimal>, due:
     -result
 }
 
+/// FV Schedule - Future Value with varying rates per period
+///
+/// Grows a present value through a sequence of per-period rates, rather than a
+/// single constant `rate` applied to every period like [`fv_internal`].
+///
+/// # Arguments
+/// * `rates` - The interest rate for each period, in chronological order
+/// * `pv` - The present value
+///
+/// # Returns
+/// * The future value of `pv` after growing through each rate in `rates`
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::fv_schedule;
+/// use rust_decimal_macros::*;
+///
+/// let rates = [dec!(0.10), dec!(0.08), dec!(0.05)];
+/// fv_schedule(&rates, dec!(1000));
+/// ```
+pub fn fv_schedule(rates: &[Decimal], pv: Decimal) -> Decimal {
+    rates.iter().fold(pv, |acc, rate| acc * (ONE + rate))
+}
+
+/// FV Schedule with payments - Future Value with varying rates and a recurring payment
+///
+/// Same as [`fv_schedule`], but additionally rolls a constant per-period `pmt`
+/// forward, each payment growing under the product of the rates for the
+/// periods remaining after it is made. This composes the varying-rate growth
+/// of [`fv_schedule`] with the existing annuity math in [`fv_internal`].
+///
+/// # Arguments
+/// * `rates` - The interest rate for each period, in chronological order
+/// * `pv` - The present value
+/// * `pmt` - The payment amount per period
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///
+/// # Returns
+/// * The future value of `pv` and the stream of `pmt` payments after growing through each rate in `rates`
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::fv_schedule_pmt;
+/// use rust_decimal_macros::*;
+///
+/// let rates = [dec!(0.10), dec!(0.08), dec!(0.05)];
+/// fv_schedule_pmt(&rates, dec!(1000), dec!(-100), None);
+/// ```
+pub fn fv_schedule_pmt(rates: &[Decimal], pv: Decimal, pmt: Decimal, due: Option<bool>) -> Decimal {
+    let due = due.unwrap_or(false);
+    let pv_grown = fv_schedule(rates, pv);
+
+    let pmt_fv = (0..rates.len()).fold(ZERO, |acc, i| {
+        let remaining = rates[i + 1..].iter().fold(ONE, |factor, rate| factor * (ONE + rate));
+        let factor = if due { remaining * (ONE + rates[i]) } else { remaining };
+        acc + pmt * factor
+    });
+
+    pv_grown + pmt_fv
+}
+
+/// FV Continuous - Future Value under continuous compounding
+///
+/// Computes the future value of a present value under continuous compounding,
+/// `pv * e^(rate * nper)`, rather than the discrete `(1 + rate)^nper`
+/// compounding used by [`fv_internal`].
+///
+/// # Arguments
+/// * `rate` - The continuously-compounded interest rate per period
+/// * `nper` - The number of periods
+/// * `pv` - The present value
+///
+/// # Returns
+/// * The future value (FV) under continuous compounding
+///
+/// # Precision
+/// [`Decimal::exp`] evaluates `e^x` as a series expansion and, like the rest
+/// of rust_decimal, is limited to 28-29 significant digits. Results stay
+/// reliable for the `rate * nper` magnitudes typical of finance (single
+/// digits); very large exponents lose precision as the series approaches
+/// rust_decimal's representable range, and will panic on overflow.
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::fv_continuous;
+/// use rust_decimal_macros::*;
+///
+/// let rate = dec!(0.05); let nper = dec!(10); let pv = dec!(1000);
+/// fv_continuous(rate, nper, pv);
+/// ```
+pub fn fv_continuous(rate: Decimal, nper: Decimal, pv: Decimal) -> Decimal {
+    let exponent = rate * nper;
+    pv * exponent.exp()
+}
+
+/// Validates the preconditions [`fv_internal`] assumes but doesn't enforce:
+/// that `rate > -1`, that `nper` is non-negative, and that at least one of
+/// `pmt`/`pv` is non-zero.
+fn validate_fv_inputs(rate: Decimal, nper: Decimal, pmt: Decimal, pv: Decimal) -> Result<(), TvmError> {
+    if pmt == ZERO && pv == ZERO {
+        return Err(TvmError::ZeroPmtAndPv);
+    }
+    if nper < ZERO {
+        return Err(TvmError::InvalidNper);
+    }
+    if rate <= -ONE {
+        return Err(TvmError::InvalidRate);
+    }
+    Ok(())
+}
+
+/// FV - Future Value (validated, Excel-compatible)
+///
+/// Like [`fv`], but validates its preconditions and returns a [`TvmError`]
+/// instead of silently producing a nonsense value when they don't hold:
+/// `rate <= -1` (makes `(1 + rate)^nper` meaningless), a negative `nper`, or
+/// both `pmt` and `pv` being zero.
+///
+/// # Arguments
+/// * `rate`, `nper`, `pmt`, `pv`, `due` - Same as [`fv`]
+/// * `silent` (optional) - When `Some(true)`, skips validation and always returns `Ok`. Default is `false`.
+///
+/// # Returns
+/// * `Ok(fv)` - The future value (FV), using Excel's sign convention
+/// * `Err(TvmError)` - If the inputs fail validation
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::try_fv;
+/// use rust_decimal_macros::*;
+///
+/// let rate = dec!(0.05); let nper = dec!(10); let pmt = dec!(-100);
+/// try_fv(rate, nper, pmt, None, None, None).unwrap();
+/// ```
+pub fn try_fv(
+    rate: Decimal,
+    nper: Decimal,
+    pmt: Decimal,
+    pv: Option<Decimal>,
+    due: Option<bool>,
+    silent: Option<bool>,
+) -> Result<Decimal, TvmError> {
+    if !silent.unwrap_or(false) {
+        validate_fv_inputs(rate, nper, pmt, pv.unwrap_or(ZERO))?;
+    }
+    Ok(fv(rate, nper, pmt, pv, due))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -224,4 +375,113 @@ mod tests {
             result3
         );
     }
+
+    #[test]
+    fn test_fv_schedule() {
+        // Constant rate schedule should match fv_internal with no pmt
+        let rates = [dec!(0.05), dec!(0.05), dec!(0.05)];
+        let result = fv_schedule(&rates, dec!(1000));
+        let expected = fv_internal(dec!(0.05), dec!(3), ZERO, Some(dec!(1000)), None);
+        assert!(
+            (result - expected).abs() < dec!(1e-10),
+            "Constant-rate schedule should match fv_internal. Expected {}, got {}",
+            expected,
+            result
+        );
+
+        // Varying rates: 1000 * 1.10 * 1.08 * 1.05
+        let varying = [dec!(0.10), dec!(0.08), dec!(0.05)];
+        let result = fv_schedule(&varying, dec!(1000));
+        assert!(
+            (result - dec!(1247.4)).abs() < dec!(1e-5),
+            "Varying-rate schedule failed. Expected 1247.4, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_fv_schedule_pmt() {
+        // Constant rate schedule with pmt should match fv_internal
+        let rates = [dec!(0.05), dec!(0.05), dec!(0.05)];
+        let result = fv_schedule_pmt(&rates, dec!(1000), dec!(-100), None);
+        let expected = fv_internal(dec!(0.05), dec!(3), dec!(-100), Some(dec!(1000)), None);
+        assert!(
+            (result - expected).abs() < dec!(1e-10),
+            "Constant-rate schedule with pmt should match fv_internal. Expected {}, got {}",
+            expected,
+            result
+        );
+
+        // Same, but payments due at the beginning of each period
+        let result_due = fv_schedule_pmt(&rates, dec!(1000), dec!(-100), Some(true));
+        let expected_due = fv_internal(dec!(0.05), dec!(3), dec!(-100), Some(dec!(1000)), Some(true));
+        assert!(
+            (result_due - expected_due).abs() < dec!(1e-10),
+            "Constant-rate schedule with pmt due should match fv_internal. Expected {}, got {}",
+            expected_due,
+            result_due
+        );
+
+        // Varying rates: pv_grown = 1000 * 1.10 * 1.08 * 1.05 = 1247.4
+        // pmt_fv = -100*(1.08*1.05) + -100*1.05 + -100*1 = -318.4
+        let varying = [dec!(0.10), dec!(0.08), dec!(0.05)];
+        let result_varying = fv_schedule_pmt(&varying, dec!(1000), dec!(-100), None);
+        assert!(
+            (result_varying - dec!(929.0)).abs() < dec!(1e-5),
+            "Varying-rate schedule with pmt failed. Expected 929.0, got {}",
+            result_varying
+        );
+    }
+
+    #[test]
+    fn test_fv_continuous() {
+        // pv * e^(rate * nper) = 1000 * e^0.5 ~= 1648.72
+        let result = fv_continuous(dec!(0.05), dec!(10), dec!(1000));
+        assert!(
+            (result - dec!(1648.72)).abs() < dec!(0.01),
+            "Continuous compounding failed. Expected ~1648.72, got {}",
+            result
+        );
+
+        // Zero rate should leave pv unchanged
+        let result = fv_continuous(ZERO, dec!(10), dec!(1000));
+        assert!(
+            (result - dec!(1000)).abs() < dec!(1e-10),
+            "Zero rate should leave pv unchanged, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_try_fv_valid() {
+        let result = try_fv(dec!(0.05), dec!(10), dec!(-100), None, None, None).unwrap();
+        let expected = fv(dec!(0.05), dec!(10), dec!(-100), None, None);
+        assert!((result - expected).abs() < dec!(1e-10), "Expected {}, got {}", expected, result);
+    }
+
+    #[test]
+    fn test_try_fv_zero_pmt_and_pv() {
+        let result = try_fv(dec!(0.05), dec!(10), ZERO, None, None, None);
+        assert!(matches!(result, Err(TvmError::ZeroPmtAndPv)));
+    }
+
+    #[test]
+    fn test_try_fv_invalid_nper() {
+        let result = try_fv(dec!(0.05), dec!(-1), dec!(-100), None, None, None);
+        assert!(matches!(result, Err(TvmError::InvalidNper)));
+    }
+
+    #[test]
+    fn test_try_fv_invalid_rate() {
+        let result = try_fv(dec!(-1), dec!(10), dec!(-100), None, None, None);
+        assert!(matches!(result, Err(TvmError::InvalidRate)));
+    }
+
+    #[test]
+    fn test_try_fv_silent_skips_validation() {
+        // Invalid under normal validation, but the silent toggle opts back into
+        // the infallible fast path and always returns Ok.
+        let result = try_fv(dec!(-1), dec!(10), ZERO, None, None, Some(true));
+        assert!(result.is_ok());
+    }
 }
@@ -0,0 +1,164 @@
+use crate::ZERO;
+use rust_decimal::prelude::*;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// A single period's record within an [`FvSolution`] series.
+///
+/// # Fields
+/// * `period` - The 1-indexed period number
+/// * `payment` - The payment applied during this period
+/// * `interest` - The interest earned during this period
+/// * `balance` - The running balance after this period's payment and interest
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FvPeriod {
+    pub period: u32,
+    pub payment: Decimal,
+    pub interest: Decimal,
+    pub balance: Decimal,
+}
+
+/// FV Solution - an auditable, period-by-period future value trace
+///
+/// Retains the inputs to a future value calculation so that, in addition to
+/// the single final answer returned by [`fv_internal`](super::fv_internal), the full period-by-period
+/// growth can be inspected via [`Self::series`]. This is what's needed when
+/// building a statement or verifying compounding rather than just checking
+/// the final number.
+///
+/// # Fields
+/// * `rate` - The interest rate per period
+/// * `nper` - The number of compounding periods
+/// * `pmt` - The payment amount per period
+/// * `pv` - The present value
+/// * `due` - The timing of the payment (false = end of period, true = beginning of period)
+pub struct FvSolution {
+    pub rate: Decimal,
+    pub nper: Decimal,
+    pub pmt: Decimal,
+    pub pv: Decimal,
+    pub due: bool,
+}
+
+/// Builds an [`FvSolution`] retaining the inputs to a future value calculation.
+///
+/// # Arguments
+/// * `rate` - The interest rate per period
+/// * `nper` - The number of compounding periods
+/// * `pmt` - The payment amount per period
+/// * `pv` (optional) - The present value, default is 0
+/// * `due` (optional) - The timing of the payment (false = end of period, true = beginning of period), default is false
+///
+/// # Returns
+/// * An [`FvSolution`] that can emit the period-by-period trace via [`FvSolution::series`]
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::fv_solution;
+/// use rust_decimal_macros::*;
+///
+/// let solution = fv_solution(dec!(0.05), dec!(10), dec!(-100), None, None);
+/// solution.series();
+/// ```
+pub fn fv_solution(rate: Decimal, nper: Decimal, pmt: Decimal, pv: Option<Decimal>, due: Option<bool>) -> FvSolution {
+    FvSolution {
+        rate,
+        nper,
+        pmt,
+        pv: pv.unwrap_or(ZERO),
+        due: due.unwrap_or(false),
+    }
+}
+
+impl FvSolution {
+    /// Returns the period-by-period trace of this solution: one [`FvPeriod`]
+    /// per compounding period, each holding the period index, the payment
+    /// applied that period, the interest earned that period, and the running
+    /// balance.
+    pub fn series(&self) -> Vec<FvPeriod> {
+        let n = self.nper.to_i64().unwrap_or(0).max(0) as u32;
+        let mut balance = self.pv;
+        let mut periods = Vec::with_capacity(n as usize);
+
+        for period in 1..=n {
+            let interest = if self.due {
+                balance += self.pmt;
+                let interest = balance * self.rate;
+                balance += interest;
+                interest
+            } else {
+                let interest = balance * self.rate;
+                balance += interest;
+                balance += self.pmt;
+                interest
+            };
+
+            periods.push(FvPeriod {
+                period,
+                payment: self.pmt,
+                interest,
+                balance,
+            });
+        }
+
+        periods
+    }
+
+    /// Pretty-prints the period-by-period schedule returned by [`Self::series`].
+    #[cfg(feature = "std")]
+    pub fn print_table(&self) {
+        println!("{:>6} | {:>15} | {:>15} | {:>15}", "Period", "Payment", "Interest", "Balance");
+        for period in self.series() {
+            println!(
+                "{:>6} | {:>15} | {:>15} | {:>15}",
+                period.period, period.payment, period.interest, period.balance
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    use crate::tvm::fv_internal;
+    use rust_decimal_macros::*;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+    #[cfg(not(feature = "std"))]
+    use std::prelude::v1::*;
+
+    #[test]
+    fn test_fv_solution_series_matches_fv() {
+        let solution = fv_solution(dec!(0.05), dec!(10), dec!(-100), None, None);
+        let series = solution.series();
+
+        assert!(series.len() == 10, "Expected 10 periods, got {}", series.len());
+
+        let last_balance = series.last().unwrap().balance;
+        let expected = fv_internal(dec!(0.05), dec!(10), dec!(-100), None, None);
+        assert!(
+            (last_balance - expected).abs() < dec!(1e-10),
+            "Final balance should match fv_internal. Expected {}, got {}",
+            expected,
+            last_balance
+        );
+    }
+
+    #[test]
+    fn test_fv_solution_series_due() {
+        let solution = fv_solution(dec!(0.05), dec!(10), dec!(-100), Some(dec!(1000)), Some(true));
+        let series = solution.series();
+
+        let last_balance = series.last().unwrap().balance;
+        let expected = fv_internal(dec!(0.05), dec!(10), dec!(-100), Some(dec!(1000)), Some(true));
+        assert!(
+            (last_balance - expected).abs() < dec!(1e-10),
+            "Final balance should match fv_internal for due payments. Expected {}, got {}",
+            expected,
+            last_balance
+        );
+    }
+}
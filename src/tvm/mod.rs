@@ -0,0 +1,14 @@
+//! TVM - Time Value of Money
+//!
+//! Functions for computing the time value of money: future value, present
+//! value, payments, and related quantities.
+
+mod error;
+mod fv;
+mod fv_solution;
+mod xirr;
+
+pub use error::*;
+pub use fv::*;
+pub use fv_solution::*;
+pub use xirr::*;
@@ -0,0 +1,210 @@
+use crate::tvm::error::TvmError;
+use crate::{ONE, ZERO};
+use chrono::NaiveDate;
+use rust_decimal::prelude::*;
+use rust_decimal_macros::dec;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+const MAX_ITERATIONS: u32 = 100;
+const TOLERANCE: Decimal = dec!(0.0000001);
+/// Newton-Raphson steps that would put `rate` outside this magnitude (or past
+/// the `(1 + rate)^t` domain boundary at `rate <= -1`) are treated as
+/// divergence rather than fed back into `powd`, which panics on overflow.
+const MAX_RATE_MAGNITUDE: Decimal = dec!(1000);
+
+/// XIRR - Internal Rate of Return for irregularly-dated cash flows
+///
+/// Solves for the annualized rate `r` satisfying
+/// `Σ amount_i / (1 + r)^((date_i - date_0) / 365) = 0`, where `date_0` is the
+/// date of the first cash flow. Unlike the rest of the `tvm` module, which
+/// assumes evenly-spaced periods, this handles cash flows that land on
+/// arbitrary dates (the common case for real-world investments).
+///
+/// Uses Newton-Raphson, starting from a 10% guess, falling back to bisection
+/// over `(-99%, 1000%)` if the derivative vanishes or Newton-Raphson fails to
+/// converge within [`MAX_ITERATIONS`].
+///
+/// # Arguments
+/// * `cashflows` - The cash flows as `(date, amount)` pairs. Order does not matter internally,
+///   but the first entry's date is used as `date_0`.
+///
+/// # Returns
+/// * `Ok(rate)` - The annualized rate of return
+/// * `Err(TvmError::NoSignChange)` - If the cash flows don't contain both a positive and a negative amount, since no root exists
+/// * `Err(TvmError::DidNotConverge)` - If no solution was found within the iteration cap, or the
+///   bisection range does not bracket a sign change
+///
+/// # Example
+/// ```
+/// use rust_finprim::tvm::xirr;
+/// use chrono::NaiveDate;
+/// use rust_decimal_macros::*;
+///
+/// let cashflows = [
+///     (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(-1000)),
+///     (NaiveDate::from_ymd_opt(2020, 7, 1).unwrap(), dec!(100)),
+///     (NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), dec!(1100)),
+/// ];
+/// xirr(&cashflows);
+/// ```
+pub fn xirr(cashflows: &[(NaiveDate, Decimal)]) -> Result<Decimal, TvmError> {
+    let has_positive = cashflows.iter().any(|(_, amount)| *amount > ZERO);
+    let has_negative = cashflows.iter().any(|(_, amount)| *amount < ZERO);
+    if !has_positive || !has_negative {
+        return Err(TvmError::NoSignChange);
+    }
+
+    let t0 = cashflows[0].0;
+    let years: Vec<Decimal> = cashflows
+        .iter()
+        .map(|(date, _)| Decimal::from((*date - t0).num_days()) / dec!(365))
+        .collect();
+
+    let f = |rate: Decimal| -> Decimal {
+        cashflows
+            .iter()
+            .zip(years.iter())
+            .fold(ZERO, |acc, ((_, amount), t)| acc + amount / (ONE + rate).powd(*t))
+    };
+
+    let f_prime = |rate: Decimal| -> Decimal {
+        cashflows
+            .iter()
+            .zip(years.iter())
+            .fold(ZERO, |acc, ((_, amount), t)| acc + (-*t * amount) / (ONE + rate).powd(*t + ONE))
+    };
+
+    let mut rate = dec!(0.1);
+    for _ in 0..MAX_ITERATIONS {
+        let f_rate = f(rate);
+        if f_rate.abs() < TOLERANCE {
+            return Ok(rate);
+        }
+
+        let f_prime_rate = f_prime(rate);
+        if f_prime_rate == ZERO {
+            break;
+        }
+
+        let next_rate = rate - f_rate / f_prime_rate;
+        if next_rate <= -ONE || next_rate.abs() > MAX_RATE_MAGNITUDE {
+            // Diverging towards (or past) the domain boundary of (1 + rate)^t;
+            // stop before it's fed back into powd and fall back to bisection.
+            break;
+        }
+        if (next_rate - rate).abs() < TOLERANCE {
+            return Ok(next_rate);
+        }
+        rate = next_rate;
+    }
+
+    // Newton-Raphson either diverged or the derivative vanished; fall back to
+    // bisection over a bracketed range.
+    bisect(&f, dec!(-0.99), dec!(10))
+}
+
+/// Bisection fallback used by [`xirr`] when Newton-Raphson fails to converge.
+fn bisect(f: &dyn Fn(Decimal) -> Decimal, mut low: Decimal, mut high: Decimal) -> Result<Decimal, TvmError> {
+    let mut f_low = f(low);
+    let f_high = f(high);
+    if f_low.signum() == f_high.signum() {
+        return Err(TvmError::DidNotConverge);
+    }
+
+    for _ in 0..MAX_ITERATIONS {
+        let mid = (low + high) / dec!(2);
+        let f_mid = f(mid);
+        if f_mid.abs() < TOLERANCE {
+            return Ok(mid);
+        }
+
+        if f_mid.signum() == f_low.signum() {
+            low = mid;
+            f_low = f_mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    Err(TvmError::DidNotConverge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::assert;
+    #[cfg(not(feature = "std"))]
+    use std::prelude::v1::*;
+
+    #[test]
+    fn test_xirr() {
+        // $1000 invested, $100 distributed after 6 months, $1100 returned after a year
+        // Verified independently via bisection on the same cash flows: ~20.94%
+        let cashflows = [
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(-1000)),
+            (NaiveDate::from_ymd_opt(2020, 7, 1).unwrap(), dec!(100)),
+            (NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), dec!(1100)),
+        ];
+        let result = xirr(&cashflows).unwrap();
+        assert!(
+            (result - dec!(0.2094298)).abs() < dec!(0.001),
+            "Expected ~0.2094298, got {}",
+            result
+        );
+    }
+
+    #[test]
+    fn test_xirr_no_sign_change() {
+        let cashflows = [
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(1000)),
+            (NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), dec!(1100)),
+        ];
+        assert!(matches!(xirr(&cashflows), Err(TvmError::NoSignChange)));
+    }
+
+    #[test]
+    fn test_xirr_simple_annual_matches_rate() {
+        // A single round trip over exactly one year should recover the simple rate of return.
+        let cashflows = [
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(-1000)),
+            (NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), dec!(1200)),
+        ];
+        let result = xirr(&cashflows).unwrap();
+        assert!((result - dec!(0.2)).abs() < dec!(0.005), "Expected ~0.2, got {}", result);
+    }
+
+    #[test]
+    fn test_xirr_mostly_worthless_investment_does_not_panic() {
+        // A near-total loss sends naive Newton-Raphson overshooting towards a
+        // huge rate, which should be caught and routed to bisection instead of
+        // panicking inside powd.
+        let cashflows = [
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(-1000)),
+            (NaiveDate::from_ymd_opt(2020, 1, 10).unwrap(), dec!(1)),
+            (NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), dec!(1)),
+        ];
+        let result = xirr(&cashflows);
+        assert!(result.is_ok() || matches!(result, Err(TvmError::DidNotConverge)));
+    }
+
+    #[test]
+    fn test_xirr_dca_then_small_payout_does_not_panic() {
+        // Repeated contributions followed by a small payout can send
+        // Newton-Raphson's next_rate past the rate <= -1 domain boundary of
+        // (1 + rate)^t; this should be caught rather than panicking.
+        let cashflows = [
+            (NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), dec!(-500)),
+            (NaiveDate::from_ymd_opt(2020, 4, 1).unwrap(), dec!(-500)),
+            (NaiveDate::from_ymd_opt(2020, 7, 1).unwrap(), dec!(-500)),
+            (NaiveDate::from_ymd_opt(2020, 10, 1).unwrap(), dec!(-500)),
+            (NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(), dec!(50)),
+        ];
+        let result = xirr(&cashflows);
+        assert!(result.is_ok() || matches!(result, Err(TvmError::DidNotConverge)));
+    }
+}